@@ -1,5 +1,5 @@
 use std::{
-    f64::consts::PI,
+    collections::HashMap,
     ops::{Add, Deref, Div, Mul, Sub},
 };
 
@@ -9,49 +9,127 @@ use crate::math::Angle;
 pub struct SkyCoord {
     ra: Angle,
     dec: Angle,
-    date: JulianDate,
+    /// The epoch at which `ra`/`dec` are expressed (i.e. the date they would
+    /// be valid without further precession).
+    epoch: JulianDate,
 }
 impl SkyCoord {
+    /// `ra`/`dec` are catalog coordinates, valid at the J2000.0 epoch.
     pub fn from_ra_dec(ra: Angle, dec: Angle) -> Self {
         Self {
             ra,
             dec,
-            date: JulianDate::J2000,
+            epoch: JulianDate::J2000,
         }
     }
 
-    pub fn from_ra_dec_date(ra: Angle, dec: Angle, date: JulianDate) -> Self {
-        Self { ra, dec, date }
+    /// `ra`/`dec` are already expressed as of `epoch` (e.g. a true-of-date
+    /// position), with no further precession implied.
+    pub fn from_ra_dec_date(ra: Angle, dec: Angle, epoch: JulianDate) -> Self {
+        Self { ra, dec, epoch }
     }
 
     pub fn ra_dec(&self) -> (Angle, Angle) {
         (self.ra, self.dec)
     }
 
-    pub fn alt_az(&self, ground_pos: GroundCoord) -> (Angle, Angle) {
-        let (lat, long) = ground_pos.lat_long();
-        //Meeus 13.5 and 13.6, modified so West longitudes are negative and 0 is North
-        let gmst = greenwich_mean_sidereal_time(self.date);
-        let local_sidereal_time = (gmst + long) % Angle::TAU;
+    /// Precesses this coordinate from its stored epoch to `date`, using the
+    /// rigorous IAU precession formulas (Meeus ch. 21).
+    ///
+    /// The angles use the coefficients for a J2000.0 starting epoch, with `t`
+    /// measured from this coordinate's stored epoch; the starting-epoch terms
+    /// of the general formula are not applied, so the result is exact only
+    /// for a coordinate stored at J2000.0.
+    ///
+    /// Returns `self` unchanged, modulo floating point error, when `date`
+    /// equals this coordinate's current epoch - so repeated calls with the
+    /// same `date`, or a call on an already-precessed coordinate, never
+    /// double-apply the correction.
+    pub fn precess_to(&self, date: JulianDate) -> SkyCoord {
+        let t = ((date - self.epoch) / JulianInterval::YEAR).julian();
 
-        let h = match local_sidereal_time - self.ra {
-            x if x.radians() < 0.0 => x + Angle::TAU,
-            x if x.radians() > PI => x - Angle::TAU,
-            x => x,
-        };
+        let zeta = Angle::from_degrees(
+            (2306.2181 * t + 0.30188 * t.powi(2) + 0.017998 * t.powi(3)) / 3600.0,
+        );
+        let z = Angle::from_degrees(
+            (2306.2181 * t + 1.09468 * t.powi(2) + 0.018203 * t.powi(3)) / 3600.0,
+        );
+        let theta = Angle::from_degrees(
+            (2004.3109 * t - 0.42665 * t.powi(2) - 0.041833 * t.powi(3)) / 3600.0,
+        );
 
-        let az = match Angle::atan2(h.sin(), h.cos() * lat.sin() - self.dec.tan() * lat.cos())
-            - Angle::PI
-        {
-            x if x.radians() < 0.0 => x + Angle::TAU,
-            x => x,
-        };
-        let alt = Angle::asin(lat.sin() * self.dec.sin() + lat.cos() * self.dec.cos() * h.cos());
+        let ra_zeta = self.ra + zeta;
+        let a = self.dec.cos() * ra_zeta.sin();
+        let b = theta.cos() * self.dec.cos() * ra_zeta.cos() - theta.sin() * self.dec.sin();
+        let c = theta.sin() * self.dec.cos() * ra_zeta.cos() + theta.cos() * self.dec.sin();
+
+        let ra = (Angle::atan2(a, b) + z).normalize();
+        let dec = Angle::asin(c);
 
-        (alt, az)
+        Self {
+            ra,
+            dec,
+            epoch: date,
+        }
+    }
+
+    /// Computes the altitude/azimuth of this coordinate as seen from
+    /// `ground_pos` at `date`, precessing it from its stored epoch to `date`
+    /// first.
+    pub fn alt_az(&self, ground_pos: GroundCoord, date: JulianDate) -> (Angle, Angle) {
+        let precessed = self.precess_to(date);
+        equatorial_to_alt_az(precessed.ra, precessed.dec, ground_pos, date)
+    }
+
+    /// Like [SkyCoord::alt_az], but corrects the altitude for atmospheric
+    /// refraction given the observer's local `pressure_mbar` and `temp_c`.
+    ///
+    /// Near the horizon refraction raises the apparent altitude by up to
+    /// ~34 arcminutes above the geometric one.
+    pub fn alt_az_apparent(
+        &self,
+        ground_pos: GroundCoord,
+        date: JulianDate,
+        pressure_mbar: f64,
+        temp_c: f64,
+    ) -> (Angle, Angle) {
+        let (alt, az) = self.alt_az(ground_pos, date);
+        (apparent_altitude(alt, pressure_mbar, temp_c), az)
     }
 }
 
+/// Converts a true (geometric) altitude to the apparent altitude after
+/// atmospheric refraction, using Bennett's formula.
+///
+/// Returns `true_alt` unchanged below -1 degrees, where the formula
+/// degenerates.
+pub fn apparent_altitude(true_alt: Angle, pressure_mbar: f64, temp_c: f64) -> Angle {
+    let h = true_alt.degrees();
+    if h < -1.0 {
+        return true_alt;
+    }
+
+    let r_arcmin = 1.0 / Angle::from_degrees(h + 7.31 / (h + 4.4)).tan();
+    let r = r_arcmin * (pressure_mbar / 1010.0) * (283.0 / (273.0 + temp_c));
+    true_alt + Angle::from_degrees(r / 60.0)
+}
+
+/// Converts an apparent (observed) altitude back to the true geometric
+/// altitude, using the Saemundsson variant of Bennett's formula.
+///
+/// Returns `apparent_alt` unchanged below -1 degrees, where the formula
+/// degenerates.
+pub fn true_altitude(apparent_alt: Angle, pressure_mbar: f64, temp_c: f64) -> Angle {
+    let h = apparent_alt.degrees();
+    if h < -1.0 {
+        return apparent_alt;
+    }
+
+    let r_arcmin = 1.02 / Angle::from_degrees(h + 10.3 / (h + 5.11)).tan();
+    let r = r_arcmin * (pressure_mbar / 1010.0) * (283.0 / (273.0 + temp_c));
+    apparent_alt - Angle::from_degrees(r / 60.0)
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 pub struct GroundCoord {
     lat: Angle,
@@ -66,39 +144,130 @@ impl GroundCoord {
     }
 }
 
-/// Stores affine transformation from pixel (x, y) to world (ra, dec)
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// Stores a FITS WCS TAN (gnomonic) projection from pixel (x, y) to world
+/// (ra, dec), with an optional SIP distortion polynomial applied in pixel
+/// space before the CD matrix.
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct WorldTransform {
-    affine: glam::DAffine2,
+    /// CD matrix, mapping a pixel offset from `crpix` to intermediate world
+    /// coordinates (xi, eta) in radians.
+    cd: glam::DMat2,
+    /// Reference pixel (CRPIX1, CRPIX2).
+    crpix: glam::DVec2,
+    /// Reference point (CRVAL1, CRVAL2): (ra0, dec0).
+    crval: (Angle, Angle),
+    /// Optional SIP distortion coefficients.
+    sip: Option<SipPolynomial>,
 }
 impl WorldTransform {
-    pub fn from_mat2_translation(mat2: [f64; 4], translation: [f64; 2]) -> Self {
+    /// `cd` and `crval` are in degrees, as stored in a FITS header; `crpix`
+    /// is in pixels.
+    pub fn new(cd: [f64; 4], crpix: [f64; 2], crval: [f64; 2]) -> Self {
         Self {
-            affine: glam::DAffine2::from_mat2_translation(
-                glam::DMat2::from_cols_array(&mat2),
-                glam::DVec2::from_array(translation),
-            ),
+            // `cd` is row-major ([CD1_1, CD1_2, CD2_1, CD2_2], as read from a
+            // FITS header), but `from_cols_array` expects column-major input;
+            // transpose so the CD1_2/CD2_1 cross terms land correctly.
+            cd: glam::DMat2::from_cols_array(&cd.map(f64::to_radians)).transpose(),
+            crpix: glam::DVec2::from_array(crpix),
+            crval: (Angle::from_degrees(crval[0]), Angle::from_degrees(crval[1])),
+            sip: None,
         }
     }
 
+    /// Attaches a SIP distortion polynomial to this transform.
+    pub fn with_sip(mut self, sip: SipPolynomial) -> Self {
+        self.sip = Some(sip);
+        self
+    }
+
     pub fn pixel_to_world(&self, pixel_coord: (f64, f64)) -> SkyCoord {
-        let ra_dec = self
-            .affine
-            .transform_point2(glam::dvec2(pixel_coord.0, pixel_coord.1));
-        SkyCoord::from_ra_dec(Angle::from_degrees(ra_dec.x), Angle::from_degrees(ra_dec.y))
+        let mut offset = glam::dvec2(pixel_coord.0, pixel_coord.1) - self.crpix;
+        if let Some(sip) = &self.sip {
+            offset = sip.apply(offset);
+        }
+
+        let intermediate = self.cd * offset;
+        let (xi, eta) = (intermediate.x, intermediate.y);
+        let (ra0, dec0) = self.crval;
+
+        let ra = ra0 + Angle::atan2(xi, dec0.cos() - eta * dec0.sin());
+        let dec =
+            Angle::asin((dec0.sin() + eta * dec0.cos()) / (1.0 + xi * xi + eta * eta).sqrt());
+
+        SkyCoord::from_ra_dec(ra.normalize(), dec)
     }
 
+    /// The inverse of [WorldTransform::pixel_to_world]. Note this does not
+    /// invert the SIP distortion, which is only applied in the pixel ->
+    /// world direction.
     pub fn world_to_pixel(&self, world_coord: SkyCoord) -> (f64, f64) {
         let (ra, dec) = world_coord.ra_dec();
-        let xy = self
-            .affine
-            .inverse()
-            .transform_point2(glam::dvec2(ra.degrees(), dec.degrees()));
-        (xy.x, xy.y)
+        let (ra0, dec0) = self.crval;
+
+        // Forward gnomonic projection onto intermediate world coordinates.
+        let d_ra = ra - ra0;
+        let cos_c = dec0.sin() * dec.sin() + dec0.cos() * dec.cos() * d_ra.cos();
+        let xi = dec.cos() * d_ra.sin() / cos_c;
+        let eta = (dec0.cos() * dec.sin() - dec0.sin() * dec.cos() * d_ra.cos()) / cos_c;
+
+        let offset = self.cd.inverse() * glam::dvec2(xi, eta) + self.crpix;
+        (offset.x, offset.y)
+    }
+}
+
+/// SIP (Simple Imaging Polynomial) distortion coefficients, applied to the
+/// pixel offset `(u, v) = pixel - crpix` before the CD matrix:
+/// `u' = u + sum(A_ij * u^i * v^j)`, `v' = v + sum(B_ij * u^i * v^j)`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SipPolynomial {
+    a: HashMap<(u32, u32), f64>,
+    b: HashMap<(u32, u32), f64>,
+}
+impl SipPolynomial {
+    pub fn from_coefficients(a: HashMap<(u32, u32), f64>, b: HashMap<(u32, u32), f64>) -> Self {
+        Self { a, b }
+    }
+
+    fn apply(&self, offset: glam::DVec2) -> glam::DVec2 {
+        let (u, v) = (offset.x, offset.y);
+        let correction = |coeffs: &HashMap<(u32, u32), f64>| -> f64 {
+            coeffs
+                .iter()
+                .map(|(&(i, j), coeff)| coeff * u.powi(i as i32) * v.powi(j as i32))
+                .sum()
+        };
+        glam::dvec2(u + correction(&self.a), v + correction(&self.b))
     }
 }
 
-fn greenwich_mean_sidereal_time(julian_date: JulianDate) -> Angle {
+/// Converts an already-of-`date` equatorial (`ra`, `dec`) direction to
+/// altitude/azimuth as seen from `ground_pos` at `date`.
+///
+/// Shared by [SkyCoord::alt_az] and the polar alignment solver, which both
+/// need to turn a date-of-epoch equatorial direction into alt/az via the
+/// observer's local sidereal time.
+pub(crate) fn equatorial_to_alt_az(
+    ra: Angle,
+    dec: Angle,
+    ground_pos: GroundCoord,
+    date: JulianDate,
+) -> (Angle, Angle) {
+    let (lat, long) = ground_pos.lat_long();
+
+    //Meeus 13.5 and 13.6, modified so West longitudes are negative and 0 is North
+    let gmst = greenwich_mean_sidereal_time(date);
+    let local_sidereal_time = (gmst + long) % Angle::TAU;
+
+    let h = (local_sidereal_time - ra).normalize_signed();
+
+    let az = (Angle::atan2(h.sin(), h.cos() * lat.sin() - dec.tan() * lat.cos()) - Angle::PI)
+        .normalize();
+    let alt = Angle::asin(lat.sin() * dec.sin() + lat.cos() * dec.cos() * h.cos());
+
+    (alt, az)
+}
+
+pub(crate) fn greenwich_mean_sidereal_time(julian_date: JulianDate) -> Angle {
     //The IAU Resolutions on Astronomical Reference Systems, Time Scales, and Earth Rotation Models Explanation and Implementation (George H. Kaplan)
     //https://arxiv.org/pdf/astro-ph/0602086.pdf
     let t = ((julian_date - JulianDate::J2000) / JulianInterval::YEAR).julian();
@@ -114,7 +283,7 @@ fn greenwich_mean_sidereal_time(julian_date: JulianDate) -> Angle {
     Angle::from_radians(gmst)
 }
 
-fn earth_rotation_angle(julian_date: JulianDate) -> Angle {
+pub(crate) fn earth_rotation_angle(julian_date: JulianDate) -> Angle {
     //https://arxiv.org/pdf/astro-ph/0602086.pdf
     let t = julian_date - JulianDate::J2000;
     let era = 0.7790572732640 + 0.00273781191135448 * t.julian() + julian_date.frac().julian();
@@ -126,6 +295,85 @@ pub struct JulianDate(f64);
 impl JulianDate {
     const J2000: Self = Self(2451545.0);
 
+    /// Constructs a `JulianDate` from a raw Julian day number.
+    pub(crate) fn from_julian(jd: f64) -> Self {
+        Self(jd)
+    }
+
+    /// Constructs a `JulianDate` from a Gregorian calendar date and
+    /// time-of-day, using the standard Meeus conversion (Meeus ch. 7).
+    ///
+    /// `hour`/`min`/`sec` are assumed to be in the same time scale as the
+    /// caller wants the result expressed in (UTC unless corrected with
+    /// [JulianDate::with_delta_t]).
+    pub fn from_gregorian(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: f64) -> Self {
+        let (year, month) = if month <= 2 {
+            (year - 1, month + 12)
+        } else {
+            (year, month)
+        };
+
+        let a = (year as f64 / 100.0).floor();
+        let b = 2.0 - a + (a / 4.0).floor();
+        let day_frac = day as f64 + (hour as f64 + min as f64 / 60.0 + sec / 3600.0) / 24.0;
+
+        let jd = (365.25 * (year as f64 + 4716.0)).floor()
+            + (30.6001 * (month as f64 + 1.0)).floor()
+            + day_frac
+            + b
+            - 1524.5;
+
+        Self(jd)
+    }
+
+    /// Constructs a `JulianDate` from a Unix timestamp (seconds since
+    /// 1970-01-01T00:00:00 UTC).
+    pub fn from_unix(secs: f64) -> Self {
+        const UNIX_EPOCH_JD: f64 = 2440587.5;
+        Self(UNIX_EPOCH_JD + secs / 86400.0)
+    }
+
+    /// Breaks this date down into its Gregorian calendar components:
+    /// `(year, month, day, hour, min, sec)`, in whatever time scale this
+    /// `JulianDate` is expressed in.
+    pub fn to_gregorian(&self) -> (i32, u32, u32, u32, u32, f64) {
+        let jd = self.0 + 0.5;
+        let z = jd.floor();
+        let f = jd - z;
+
+        let a = if z < 2299161.0 {
+            z
+        } else {
+            let alpha = ((z - 1867216.25) / 36524.25).floor();
+            z + 1.0 + alpha - (alpha / 4.0).floor()
+        };
+        let b = a + 1524.0;
+        let c = ((b - 122.1) / 365.25).floor();
+        let d = (365.25 * c).floor();
+        let e = ((b - d) / 30.6001).floor();
+
+        let day_frac = b - d - (30.6001 * e).floor() + f;
+        let month = if e < 14.0 { e - 1.0 } else { e - 13.0 };
+        let year = if month > 2.0 { c - 4716.0 } else { c - 4715.0 };
+
+        let day = day_frac.floor();
+        let hours = (day_frac - day) * 24.0;
+        let hour = hours.floor();
+        let mins = (hours - hour) * 60.0;
+        let min = mins.floor();
+        let sec = (mins - min) * 60.0;
+
+        (year as i32, month as u32, day as u32, hour as u32, min as u32, sec)
+    }
+
+    /// Shifts this date by a UTC -> TT (or other time scale) correction,
+    /// `delta_t_secs` seconds. Sidereal-time computations in this crate
+    /// treat their input as UTC by default; apply this first if you have a
+    /// leap-second/`ΔT` correction to feed them UT1 or TT instead.
+    pub fn with_delta_t(&self, delta_t_secs: f64) -> Self {
+        Self(self.0 + delta_t_secs / 86400.0)
+    }
+
     fn julian(&self) -> f64 {
         self.0
     }
@@ -154,7 +402,7 @@ pub struct JulianInterval(f64);
 impl JulianInterval {
     const YEAR: Self = Self(36525.0);
 
-    fn julian(&self) -> f64 {
+    pub(crate) fn julian(&self) -> f64 {
         self.0
     }
 }
@@ -186,3 +434,141 @@ impl Div for JulianInterval {
         JulianInterval(self.0 / rhs.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pixel_to_world_respects_cd_matrix_orientation() {
+        // A non-symmetric CD matrix (CD1_2 != CD2_1, as in any plate solve
+        // with field rotation) exercises the cross terms that a column/row
+        // major mixup would silently transpose.
+        let cd_deg = [1.0e-3, 4.0e-4, -6.0e-4, 9.0e-4];
+        let crpix = [512.0, 512.0];
+        let crval = [10.0, 20.0];
+        let transform = WorldTransform::new(cd_deg, crpix, crval);
+
+        let pixel = (612.0, 550.0);
+        let world = transform.pixel_to_world(pixel);
+
+        // Re-derive the expected (ra, dec) directly from the TAN projection
+        // formulas, applying the CD matrix in its documented row-major layout.
+        let (u, v) = (pixel.0 - crpix[0], pixel.1 - crpix[1]);
+        let cd = cd_deg.map(f64::to_radians);
+        let xi = cd[0] * u + cd[1] * v;
+        let eta = cd[2] * u + cd[3] * v;
+        let (ra0, dec0) = (Angle::from_degrees(crval[0]), Angle::from_degrees(crval[1]));
+        let expected_ra = (ra0 + Angle::atan2(xi, dec0.cos() - eta * dec0.sin())).normalize();
+        let expected_dec =
+            Angle::asin((dec0.sin() + eta * dec0.cos()) / (1.0 + xi * xi + eta * eta).sqrt());
+
+        let (ra, dec) = world.ra_dec();
+        assert!((ra.degrees() - expected_ra.degrees()).abs() < 1e-9);
+        assert!((dec.degrees() - expected_dec.degrees()).abs() < 1e-9);
+
+        let round_trip = transform.world_to_pixel(world);
+        assert!((round_trip.0 - pixel.0).abs() < 1e-6);
+        assert!((round_trip.1 - pixel.1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn precess_to_same_epoch_is_a_no_op() {
+        // Before being made epoch-aware, `precess_to` always measured `t`
+        // from J2000, so "precessing" a non-J2000-dated coordinate to its
+        // own epoch was *not* a no-op - it silently applied a spurious
+        // correction. A coordinate precessed to its own epoch must come
+        // back unchanged.
+        let date = JulianDate::from_gregorian(2050, 6, 15, 0, 0, 0.0);
+        let coord = SkyCoord::from_ra_dec_date(
+            Angle::from_degrees(123.456),
+            Angle::from_degrees(-45.678),
+            date,
+        );
+
+        let precessed = coord.precess_to(date);
+
+        let (ra0, dec0) = coord.ra_dec();
+        let (ra1, dec1) = precessed.ra_dec();
+        assert!((ra0.degrees() - ra1.degrees()).abs() < 1e-9);
+        assert!((dec0.degrees() - dec1.degrees()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn precess_to_a_different_epoch_actually_moves_the_coordinate() {
+        let coord = SkyCoord::from_ra_dec(Angle::from_degrees(123.456), Angle::from_degrees(45.0));
+        let future = JulianDate::from_gregorian(2050, 1, 1, 0, 0, 0.0);
+
+        let precessed = coord.precess_to(future);
+
+        let (ra0, _) = coord.ra_dec();
+        let (ra1, _) = precessed.ra_dec();
+        assert!((ra0.degrees() - ra1.degrees()).abs() > 1e-4);
+    }
+
+    #[test]
+    fn apparent_altitude_matches_bennetts_reference_value_at_the_horizon() {
+        // At standard pressure/temperature (1010 mbar, 10C) the scaling
+        // factor is exactly 1.0, leaving Bennett's raw formula: refraction at
+        // the horizon (h = 0) is ~34.5 arcminutes.
+        let apparent = apparent_altitude(Angle::from_degrees(0.0), 1010.0, 10.0);
+        let refraction_arcmin = apparent.degrees() * 60.0;
+        assert!((refraction_arcmin - 34.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn true_altitude_round_trips_through_apparent_altitude() {
+        let true_alt = Angle::from_degrees(25.0);
+        let apparent = apparent_altitude(true_alt, 1013.25, 15.0);
+        let recovered = true_altitude(apparent, 1013.25, 15.0);
+        assert!((recovered.degrees() - true_alt.degrees()).abs() < 1e-2);
+    }
+
+    #[test]
+    fn refraction_is_a_no_op_below_the_horizon_guard() {
+        let alt = Angle::from_degrees(-5.0);
+        assert_eq!(apparent_altitude(alt, 1010.0, 10.0), alt);
+        assert_eq!(true_altitude(alt, 1010.0, 10.0), alt);
+    }
+
+    #[test]
+    fn from_gregorian_matches_known_epochs() {
+        // 2000-01-01T12:00:00 is the J2000.0 epoch by definition.
+        assert_eq!(JulianDate::from_gregorian(2000, 1, 1, 12, 0, 0.0), JulianDate::J2000);
+
+        // The Unix epoch is JD 2440587.5.
+        let unix_epoch = JulianDate::from_gregorian(1970, 1, 1, 0, 0, 0.0);
+        assert!((unix_epoch.julian() - 2440587.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_unix_matches_from_gregorian_at_the_unix_epoch() {
+        let from_unix = JulianDate::from_unix(0.0);
+        let from_gregorian = JulianDate::from_gregorian(1970, 1, 1, 0, 0, 0.0);
+        assert!((from_unix.julian() - from_gregorian.julian()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_gregorian_round_trips_through_from_gregorian() {
+        let cases = [
+            (2000, 1, 1, 12, 0, 0.0),
+            (1970, 1, 1, 0, 0, 0.0),
+            (2050, 6, 15, 18, 30, 45.0),
+            (1999, 12, 31, 23, 59, 59.0),
+        ];
+
+        for (year, month, day, hour, min, sec) in cases {
+            let date = JulianDate::from_gregorian(year, month, day, hour, min, sec);
+            let (y, mo, d, h, mi, s) = date.to_gregorian();
+            assert_eq!((y, mo, d, h, mi), (year, month, day, hour, min));
+            assert!((s - sec).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn with_delta_t_shifts_by_the_given_number_of_seconds() {
+        let date = JulianDate::from_gregorian(2000, 1, 1, 12, 0, 0.0);
+        let shifted = date.with_delta_t(86400.0);
+        assert!((shifted.julian() - date.julian() - 1.0).abs() < 1e-9);
+    }
+}
@@ -2,6 +2,8 @@
 
 pub mod common;
 pub mod math;
+pub mod polar;
+pub mod satellite;
 pub mod solver;
 
 #[cfg(test)]
@@ -46,6 +46,39 @@ impl Angle {
         Self(((self.0 % 360.0) + 360.0) % 360.0)
     }
 
+    /// Normalizes the angle to (-180.0, 180.0] degrees.
+    ///
+    /// Useful for hour-angle style math where the sign of the result
+    /// indicates direction (east/west of the meridian) rather than a plain
+    /// [0, 360) bearing.
+    pub fn normalize_signed(&self) -> Self {
+        let mut deg = self.0 % 360.0;
+        if deg <= -180.0 {
+            deg += 360.0;
+        } else if deg > 180.0 {
+            deg -= 360.0;
+        }
+        Self(deg)
+    }
+
+    /// Smallest signed rotation from `self` to `other`, in (-180.0, 180.0]
+    /// degrees.
+    pub fn angle_between(&self, other: Self) -> Self {
+        (other - *self).normalize_signed()
+    }
+
+    /// Magnitude of the smallest separation between `self` and `other`, in
+    /// [0.0, 180.0] degrees.
+    pub fn abs_diff(&self, other: Self) -> Self {
+        Self(self.angle_between(other).0.abs())
+    }
+
+    /// Linearly interpolates from `self` to `other` by `t`, taking the
+    /// shorter way around the circle.
+    pub fn lerp(&self, other: Self, t: f64) -> Self {
+        (*self + self.angle_between(other) * t).normalize()
+    }
+
     pub fn sin(&self) -> f64 {
         self.0.to_radians().sin()
     }
@@ -91,16 +124,16 @@ impl Sub for Angle {
         Self(self.0 - rhs.0)
     }
 }
-impl Mul for Angle {
+impl Mul<f64> for Angle {
     type Output = Self;
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self(self.0 * rhs.0)
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
     }
 }
-impl Div for Angle {
+impl Div<f64> for Angle {
     type Output = Self;
-    fn div(self, rhs: Self) -> Self::Output {
-        Self(self.0 / rhs.0)
+    fn div(self, rhs: f64) -> Self::Output {
+        Self(self.0 / rhs)
     }
 }
 impl Rem for Angle {
@@ -109,3 +142,41 @@ impl Rem for Angle {
         Self(self.0 % rhs.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_signed_wraps_to_plus_minus_180() {
+        assert_eq!(Angle::from_degrees(200.0).normalize_signed().degrees(), -160.0);
+        assert_eq!(Angle::from_degrees(-200.0).normalize_signed().degrees(), 160.0);
+        assert_eq!(Angle::from_degrees(180.0).normalize_signed().degrees(), 180.0);
+        assert_eq!(Angle::from_degrees(-180.0).normalize_signed().degrees(), 180.0);
+        assert_eq!(Angle::from_degrees(0.0).normalize_signed().degrees(), 0.0);
+    }
+
+    #[test]
+    fn angle_between_takes_the_short_way_around() {
+        let a = Angle::from_degrees(10.0);
+        let b = Angle::from_degrees(350.0);
+        assert!((a.angle_between(b).degrees() - (-20.0)).abs() < 1e-9);
+        assert!((b.angle_between(a).degrees() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn abs_diff_is_always_non_negative() {
+        let a = Angle::from_degrees(10.0);
+        let b = Angle::from_degrees(350.0);
+        assert!((a.abs_diff(b).degrees() - 20.0).abs() < 1e-9);
+        assert!((b.abs_diff(a).degrees() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn lerp_interpolates_the_short_way_around_the_wrap() {
+        let a = Angle::from_degrees(350.0);
+        let b = Angle::from_degrees(10.0);
+        let mid = a.lerp(b, 0.5);
+        assert!((mid.degrees() - 0.0).abs() < 1e-9);
+    }
+}
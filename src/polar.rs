@@ -0,0 +1,82 @@
+//! Three-point polar alignment solver
+//!
+//! Given three [PlateSolveResult]s captured while the mount rotates about its
+//! RA axis, recovers the mount's physical rotation axis and reports how far
+//! it is from the celestial pole as an altitude/azimuth correction.
+
+use thiserror::Error;
+
+use crate::common::{equatorial_to_alt_az, GroundCoord, JulianDate, SkyCoord};
+use crate::math::Angle;
+use crate::solver::common::PlateSolveResult;
+
+/// Altitude/azimuth offset of the mount's rotation axis from the celestial
+/// pole.
+///
+/// `alt` and `az` are the axis position minus the pole position: subtract
+/// `alt` from the mount's current altitude setting and `az` from its current
+/// azimuth setting to correct the alignment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarAlignment {
+    pub alt: Angle,
+    pub az: Angle,
+}
+
+/// Minimum length of the cross product of the two chord vectors below which
+/// the three points are considered too close to collinear to define a plane.
+const MIN_NORMAL_LEN: f64 = 1e-9;
+
+/// Solves for the polar alignment error given three plate solves captured
+/// while the mount rotates about its RA axis, at the instant `date` (used to
+/// convert the recovered axis from equatorial to alt/az coordinates).
+///
+/// As the mount turns, the three pointings lie on a common small circle
+/// centered on the mount's rotation axis, so that axis is recovered as the
+/// normal of the plane through the three points on the unit sphere.
+pub fn solve(
+    solves: [PlateSolveResult; 3],
+    ground_pos: GroundCoord,
+    date: JulianDate,
+) -> Result<PolarAlignment, PolarAlignmentError> {
+    // The plate solves are J2000 catalog coordinates; precess each to `date`
+    // first so the recovered axis is expressed in the same true-of-date
+    // frame used below to convert it to alt/az.
+    let p = solves.map(|s| to_unit_vector(s.coord.precess_to(date)));
+
+    let v1 = p[1] - p[0];
+    let v2 = p[2] - p[0];
+    let normal = v1.cross(v2);
+    if normal.length() < MIN_NORMAL_LEN {
+        return Err(PolarAlignmentError::CollinearPoints);
+    }
+
+    // Orient the axis toward +z (the northern hemisphere's pole).
+    let mut axis = normal.normalize();
+    if axis.z < 0.0 {
+        axis = -axis;
+    }
+
+    let dec_axis = Angle::asin(axis.z);
+    let ra_axis = Angle::atan2(axis.y, axis.x);
+
+    let (alt, az) = equatorial_to_alt_az(ra_axis, dec_axis, ground_pos, date);
+
+    // The true pole sits at (alt = latitude, az = 0/north).
+    let (lat, _long) = ground_pos.lat_long();
+    let d_alt = alt - lat;
+    let d_az = az.normalize_signed();
+
+    Ok(PolarAlignment { alt: d_alt, az: d_az })
+}
+
+fn to_unit_vector(coord: SkyCoord) -> glam::DVec3 {
+    let (ra, dec) = coord.ra_dec();
+    glam::dvec3(dec.cos() * ra.cos(), dec.cos() * ra.sin(), dec.sin())
+}
+
+/// Errors from solving a three-point polar alignment
+#[derive(Error, Debug)]
+pub enum PolarAlignmentError {
+    #[error("plate solve points are nearly collinear; rotate the mount further between exposures")]
+    CollinearPoints,
+}
@@ -0,0 +1,355 @@
+//! Satellite tracking via SGP-4 propagation of a two-line element set
+//!
+//! Parses a TLE, propagates it to a requested [JulianDate] to recover the
+//! satellite's position, and converts that position to a [SkyCoord] (and,
+//! given a [GroundCoord], a topocentric alt/az) so bright satellites like
+//! the ISS can be tracked the same way stars are.
+//!
+//! This is a simplified near-earth propagator: it models the secular J2
+//! nodal/apsidal precession and the mean-motion derivative terms already
+//! carried in the TLE, but not the full NORAD SGP4 ballistic-coefficient
+//! drag perturbation. Predictions slowly diverge from a reference SGP4 over
+//! multi-week spans, which is fine for same-session visual tracking but not
+//! for precision orbit determination.
+
+use thiserror::Error;
+
+use crate::common::{earth_rotation_angle, GroundCoord, JulianDate, SkyCoord};
+use crate::math::Angle;
+
+const XKE: f64 = 0.07436691613317341;
+const J2: f64 = 1.082616e-3;
+const MINUTES_PER_DAY: f64 = 1440.0;
+const XKMPER: f64 = 6378.135;
+const TAU: f64 = std::f64::consts::TAU;
+
+/// A parsed two-line element set (TLE) for an Earth-orbiting satellite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tle {
+    pub name: Option<String>,
+    pub epoch: JulianDate,
+    pub inclination: Angle,
+    pub raan: Angle,
+    pub eccentricity: f64,
+    pub arg_perigee: Angle,
+    pub mean_anomaly: Angle,
+    /// Mean motion, revolutions per day.
+    pub mean_motion: f64,
+    /// First derivative of mean motion / 2, revolutions per day^2.
+    pub mean_motion_dot: f64,
+    /// Second derivative of mean motion / 6, revolutions per day^3.
+    pub mean_motion_ddot: f64,
+    /// Drag term, earth radii^-1.
+    pub bstar: f64,
+}
+impl Tle {
+    /// Parses a two-line element set (without the optional name line).
+    pub fn parse(line1: &str, line2: &str) -> Result<Self, SatelliteError> {
+        Self::parse_impl(None, line1, line2)
+    }
+
+    /// Parses a three-line element set (name line followed by the two TLE
+    /// lines).
+    pub fn parse_named(name: &str, line1: &str, line2: &str) -> Result<Self, SatelliteError> {
+        Self::parse_impl(Some(name.trim().to_string()), line1, line2)
+    }
+
+    fn parse_impl(name: Option<String>, line1: &str, line2: &str) -> Result<Self, SatelliteError> {
+        if line1.len() < 69 || line2.len() < 69 {
+            return Err(SatelliteError::MalformedTle);
+        }
+
+        let epoch_year: i32 = field(line1, 18, 20)?.parse()?;
+        let epoch_year = if epoch_year < 57 {
+            2000 + epoch_year
+        } else {
+            1900 + epoch_year
+        };
+        let epoch_day: f64 = field(line1, 20, 32)?.parse()?;
+
+        let mean_motion_dot = parse_signed_decimal(field(line1, 33, 43)?)?;
+        let mean_motion_ddot = parse_exp_notation(field(line1, 44, 52)?)?;
+        let bstar = parse_exp_notation(field(line1, 53, 61)?)?;
+
+        let inclination: f64 = field(line2, 8, 16)?.parse()?;
+        let raan: f64 = field(line2, 17, 25)?.parse()?;
+        let eccentricity: f64 = format!("0.{}", field(line2, 26, 33)?).parse()?;
+        let arg_perigee: f64 = field(line2, 34, 42)?.parse()?;
+        let mean_anomaly: f64 = field(line2, 43, 51)?.parse()?;
+        let mean_motion: f64 = field(line2, 52, 63)?.parse()?;
+
+        Ok(Self {
+            name,
+            epoch: epoch_to_julian_date(epoch_year, epoch_day),
+            inclination: Angle::from_degrees(inclination),
+            raan: Angle::from_degrees(raan),
+            eccentricity,
+            arg_perigee: Angle::from_degrees(arg_perigee),
+            mean_anomaly: Angle::from_degrees(mean_anomaly),
+            mean_motion,
+            mean_motion_dot,
+            mean_motion_ddot,
+            bstar,
+        })
+    }
+}
+
+fn field<'a>(line: &'a str, start: usize, end: usize) -> Result<&'a str, SatelliteError> {
+    line.get(start..end)
+        .map(str::trim)
+        .ok_or(SatelliteError::MalformedTle)
+}
+
+/// Parses a field with an assumed leading decimal point, e.g. `-.00002182`.
+fn parse_signed_decimal(field: &str) -> Result<f64, SatelliteError> {
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    let normalized = match field.strip_prefix('-') {
+        Some(rest) => format!("-0{rest}"),
+        None => format!("0{}", field.strip_prefix('+').unwrap_or(field)),
+    };
+    Ok(normalized.parse::<f64>()?)
+}
+
+/// Parses a TLE exponential-notation field, e.g. `-11606-4` meaning
+/// `-0.11606e-4`.
+fn parse_exp_notation(field: &str) -> Result<f64, SatelliteError> {
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    let (sign, rest) = match field.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, field.strip_prefix('+').unwrap_or(field)),
+    };
+    let split = rest
+        .len()
+        .checked_sub(2)
+        .ok_or(SatelliteError::MalformedTle)?;
+    let (mantissa, exponent) = rest.split_at(split);
+    let mantissa: f64 = format!("0.{mantissa}").parse()?;
+    let exponent: f64 = exponent.parse()?;
+    Ok(sign * mantissa * 10f64.powf(exponent))
+}
+
+fn epoch_to_julian_date(year: i32, day_of_year: f64) -> JulianDate {
+    let year = year as f64;
+    let jd_jan1 = 367.0 * year - (1.75 * year).floor() + 1721044.5;
+    JulianDate::from_julian(jd_jan1 + (day_of_year - 1.0))
+}
+
+/// A satellite being tracked from its two-line element set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Satellite {
+    tle: Tle,
+}
+impl Satellite {
+    pub fn from_tle(tle: Tle) -> Self {
+        Self { tle }
+    }
+
+    pub fn tle(&self) -> &Tle {
+        &self.tle
+    }
+
+    /// Computes the satellite's geocentric [SkyCoord] at `date`.
+    pub fn position(&self, date: JulianDate) -> Result<SkyCoord, SatelliteError> {
+        let r_sat_teme = self.propagate_teme(date)?;
+
+        let ra = Angle::atan2(r_sat_teme.y, r_sat_teme.x).normalize();
+        let dec = Angle::asin(r_sat_teme.z / r_sat_teme.length());
+
+        Ok(SkyCoord::from_ra_dec_date(ra, dec, date))
+    }
+
+    /// Computes the satellite's topocentric altitude/azimuth as seen from
+    /// `ground_pos` at `date`.
+    pub fn alt_az(
+        &self,
+        date: JulianDate,
+        ground_pos: GroundCoord,
+    ) -> Result<(Angle, Angle), SatelliteError> {
+        let r_sat_teme = self.propagate_teme(date)?;
+
+        let theta = earth_rotation_angle(date).radians();
+        let r_sat_ecef = rotate_z(r_sat_teme, theta);
+
+        let (lat, long) = ground_pos.lat_long();
+        let r_obs_ecef =
+            XKMPER * glam::dvec3(lat.cos() * long.cos(), lat.cos() * long.sin(), lat.sin());
+
+        let rho = r_sat_ecef - r_obs_ecef;
+
+        let s = lat.sin() * long.cos() * rho.x + lat.sin() * long.sin() * rho.y
+            - lat.cos() * rho.z;
+        let e = -long.sin() * rho.x + long.cos() * rho.y;
+        let z =
+            lat.cos() * long.cos() * rho.x + lat.cos() * long.sin() * rho.y + lat.sin() * rho.z;
+
+        let alt = Angle::asin(z / rho.length());
+        let az = Angle::atan2(e, -s).normalize();
+
+        Ok((alt, az))
+    }
+
+    /// Propagates this satellite's TLE to `date`, returning its TEME
+    /// position in kilometers.
+    fn propagate_teme(&self, date: JulianDate) -> Result<glam::DVec3, SatelliteError> {
+        let tle = &self.tle;
+        if !(0.0..1.0).contains(&tle.eccentricity) {
+            return Err(SatelliteError::InvalidEccentricity(tle.eccentricity));
+        }
+        let tsince = (date - tle.epoch).julian() * MINUTES_PER_DAY;
+
+        let n0 = tle.mean_motion * TAU / MINUTES_PER_DAY;
+        let i0 = tle.inclination.radians();
+        let e0 = tle.eccentricity;
+        let cosio = i0.cos();
+        let sinio = i0.sin();
+        let theta2 = cosio * cosio;
+        let x3thm1 = 3.0 * theta2 - 1.0;
+        let eosq = e0 * e0;
+        let betao2 = 1.0 - eosq;
+        let betao = betao2.sqrt();
+
+        // Recover the "proper" (unperturbed by secular J2) mean motion and
+        // semi-major axis, per Hoots & Roehrich (Spacetrack Report #3).
+        let a1 = (XKE / n0).powf(2.0 / 3.0);
+        let del1 = 0.75 * J2 * x3thm1 / (a1 * a1 * betao * betao2);
+        let ao = a1 * (1.0 - del1 * (1.0 / 3.0 + del1 * (1.0 + 134.0 / 81.0 * del1)));
+        let delo = 0.75 * J2 * x3thm1 / (ao * ao * betao * betao2);
+        let nodp = n0 / (1.0 + delo);
+        let aodp = ao / (1.0 - delo);
+
+        if aodp * (1.0 - e0) < 1.0 {
+            return Err(SatelliteError::Decayed);
+        }
+
+        // Secular J2 perturbation: nodal regression, apsidal precession, and
+        // the resulting mean-anomaly rate correction.
+        let p = aodp * betao2;
+        let base = nodp * J2 / (p * p);
+        let raan_dot = -1.5 * base * cosio;
+        let argp_dot = 0.75 * base * (5.0 * theta2 - 1.0);
+        let mdot_pert = 0.75 * base * betao * x3thm1;
+
+        let ndot = tle.mean_motion_dot * TAU / (MINUTES_PER_DAY * MINUTES_PER_DAY);
+        let nddot = tle.mean_motion_ddot * TAU / (MINUTES_PER_DAY * MINUTES_PER_DAY * MINUTES_PER_DAY);
+
+        let raan = tle.raan.radians() + raan_dot * tsince;
+        let argp = tle.arg_perigee.radians() + argp_dot * tsince;
+        let m = (tle.mean_anomaly.radians()
+            + (nodp + mdot_pert) * tsince
+            + ndot * tsince.powi(2)
+            + nddot * tsince.powi(3))
+        .rem_euclid(TAU);
+
+        // Solve Kepler's equation M = E - e*sin(E) by Newton-Raphson.
+        let mut ecc_anomaly = m;
+        for _ in 0..10 {
+            let delta = (ecc_anomaly - e0 * ecc_anomaly.sin() - m) / (1.0 - e0 * ecc_anomaly.cos());
+            ecc_anomaly -= delta;
+            if delta.abs() < 1e-12 {
+                break;
+            }
+        }
+
+        let sin_e = ecc_anomaly.sin();
+        let cos_e = ecc_anomaly.cos();
+        let r = aodp * (1.0 - e0 * cos_e);
+        let true_anomaly = (betao * sin_e).atan2(cos_e - e0);
+
+        let x_pf = r * true_anomaly.cos();
+        let y_pf = r * true_anomaly.sin();
+
+        let (sin_raan, cos_raan) = raan.sin_cos();
+        let (sin_argp, cos_argp) = argp.sin_cos();
+
+        let px = cos_raan * cos_argp - sin_raan * sin_argp * cosio;
+        let py = sin_raan * cos_argp + cos_raan * sin_argp * cosio;
+        let qx = -cos_raan * sin_argp - sin_raan * cos_argp * cosio;
+        let qy = -sin_raan * sin_argp + cos_raan * cos_argp * cosio;
+        let pz = sin_argp * sinio;
+        let qz = cos_argp * sinio;
+
+        Ok(glam::dvec3(
+            x_pf * px + y_pf * qx,
+            x_pf * py + y_pf * qy,
+            x_pf * pz + y_pf * qz,
+        ) * XKMPER)
+    }
+}
+
+fn rotate_z(v: glam::DVec3, theta: f64) -> glam::DVec3 {
+    let (sin_t, cos_t) = theta.sin_cos();
+    glam::dvec3(v.x * cos_t + v.y * sin_t, -v.x * sin_t + v.y * cos_t, v.z)
+}
+
+/// Errors from parsing or propagating a satellite's two-line element set
+#[derive(Error, Debug)]
+pub enum SatelliteError {
+    #[error("malformed two-line element set")]
+    MalformedTle,
+    #[error(transparent)]
+    ParseFloatError(#[from] std::num::ParseFloatError),
+    #[error(transparent)]
+    ParseIntError(#[from] std::num::ParseIntError),
+    #[error("eccentricity {0} is out of the valid [0, 1) range")]
+    InvalidEccentricity(f64),
+    #[error("satellite has decayed (perigee is below the Earth's surface)")]
+    Decayed,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::common::GroundCoord;
+
+    // A standard published ISS two-line element set.
+    const TLE_LINE1: &str = "1 25544U 98067A   21275.54231356  .00000335  00000-0  15527-4 0  9993";
+    const TLE_LINE2: &str = "2 25544  51.6455 166.9957 0004545 132.5264 325.7264 15.48878683303693";
+
+    #[test]
+    fn parses_a_standard_two_line_element_set() {
+        let tle = Tle::parse(TLE_LINE1, TLE_LINE2).unwrap();
+        assert_eq!(tle.inclination.degrees(), 51.6455);
+        assert_eq!(tle.raan.degrees(), 166.9957);
+        assert_eq!(tle.eccentricity, 0.0004545);
+        assert_eq!(tle.arg_perigee.degrees(), 132.5264);
+        assert_eq!(tle.mean_anomaly.degrees(), 325.7264);
+        assert_eq!(tle.mean_motion, 15.48878683);
+    }
+
+    #[test]
+    fn position_and_alt_az_stay_in_range_shortly_after_epoch() {
+        let tle = Tle::parse(TLE_LINE1, TLE_LINE2).unwrap();
+        let sat = Satellite::from_tle(tle.clone());
+
+        // A few minutes after epoch, well within the regime where this
+        // secular-only propagator is expected to track closely.
+        let date = JulianDate::from_julian(*tle.epoch + 5.0 / MINUTES_PER_DAY);
+
+        let coord = sat.position(date).unwrap();
+        let (ra, dec) = coord.ra_dec();
+        assert!((0.0..360.0).contains(&ra.degrees()));
+        assert!((-90.0..=90.0).contains(&dec.degrees()));
+
+        let ground_pos =
+            GroundCoord::from_lat_long(Angle::from_degrees(28.5), Angle::from_degrees(-80.6));
+        let (alt, az) = sat.alt_az(date, ground_pos).unwrap();
+        assert!((-90.0..=90.0).contains(&alt.degrees()));
+        assert!((0.0..360.0).contains(&az.degrees()));
+    }
+
+    #[test]
+    fn rejects_an_out_of_range_eccentricity() {
+        let mut tle = Tle::parse(TLE_LINE1, TLE_LINE2).unwrap();
+        tle.eccentricity = 1.5;
+        let sat = Satellite::from_tle(tle);
+
+        assert!(matches!(
+            sat.position(JulianDate::from_julian(0.0)),
+            Err(SatelliteError::InvalidEccentricity(_))
+        ));
+    }
+}
@@ -21,6 +21,7 @@
 
 use thiserror::Error;
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
@@ -30,7 +31,7 @@ use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
 
-use crate::common::{AstroCoord, WorldTransform};
+use crate::common::{SipPolynomial, SkyCoord, WorldTransform};
 use crate::math::Angle;
 use crate::solver::common;
 
@@ -144,29 +145,59 @@ fn parse_wcs(wcs_path: &Path) -> Result<PlateSolveResult, AstapSolverError> {
     // Parse resulting .wcs file
     let mut ra: f64 = 0.0;
     let mut dec: f64 = 0.0;
+    let mut crpix = [0.0; 2];
     let mut cd = [0.0; 4];
+    let mut sip_a = HashMap::new();
+    let mut sip_b = HashMap::new();
 
     let reader = BufReader::new(File::open(wcs_path)?);
     for line in reader.lines() {
         let line = line?;
+        let key = line.get(0..8).unwrap_or("").trim();
         let contents = line.get(10..31).unwrap_or("").trim();
-        match &line[0..8] {
-            "CRVAL1  " => ra = contents.parse::<f64>()?,
-            "CRVAL2  " => dec = contents.parse::<f64>()?,
-            "CD1_1   " => cd[0] = contents.parse::<f64>()?,
-            "CD1_2   " => cd[1] = contents.parse::<f64>()?,
-            "CD2_1   " => cd[2] = contents.parse::<f64>()?,
-            "CD2_2   " => cd[3] = contents.parse::<f64>()?,
+        match key {
+            "CRVAL1" => ra = contents.parse::<f64>()?,
+            "CRVAL2" => dec = contents.parse::<f64>()?,
+            "CRPIX1" => crpix[0] = contents.parse::<f64>()?,
+            "CRPIX2" => crpix[1] = contents.parse::<f64>()?,
+            "CD1_1" => cd[0] = contents.parse::<f64>()?,
+            "CD1_2" => cd[1] = contents.parse::<f64>()?,
+            "CD2_1" => cd[2] = contents.parse::<f64>()?,
+            "CD2_2" => cd[3] = contents.parse::<f64>()?,
+            _ if key.starts_with("A_") => {
+                if let Some(order) = parse_sip_key(key) {
+                    sip_a.insert(order, contents.parse::<f64>()?);
+                }
+            }
+            _ if key.starts_with("B_") => {
+                if let Some(order) = parse_sip_key(key) {
+                    sip_b.insert(order, contents.parse::<f64>()?);
+                }
+            }
             _ => (),
         }
     }
 
+    let mut transform = WorldTransform::new(cd, crpix, [ra, dec]);
+    if !sip_a.is_empty() || !sip_b.is_empty() {
+        transform = transform.with_sip(SipPolynomial::from_coefficients(sip_a, sip_b));
+    }
+
     Ok(PlateSolveResult {
-        coord: AstroCoord::from_ra_dec(Angle::from_degrees(ra), Angle::from_degrees(dec)),
-        transform: WorldTransform::new(cd, [ra, dec]),
+        coord: SkyCoord::from_ra_dec(Angle::from_degrees(ra), Angle::from_degrees(dec)),
+        transform,
     })
 }
 
+/// Parses a SIP distortion key such as `A_1_2` into its (i, j) powers.
+fn parse_sip_key(key: &str) -> Option<(u32, u32)> {
+    let mut parts = key.splitn(3, '_');
+    parts.next()?;
+    let i = parts.next()?.parse().ok()?;
+    let j = parts.next()?.parse().ok()?;
+    Some((i, j))
+}
+
 /// Errors from creating the wrapper
 /// Likely to occur if you have not followed setup instructions
 #[derive(Error, Debug)]
@@ -209,7 +240,7 @@ mod test {
         assert_eq!(
             result,
             PlateSolveResult {
-                coord: AstroCoord::from_ra_dec(
+                coord: SkyCoord::from_ra_dec(
                     Angle::from_degrees(234.5683671466),
                     Angle::from_degrees(88.14896797072)
                 ),
@@ -220,6 +251,7 @@ mod test {
                         0.006309995699828,
                         0.0005179551839743
                     ],
+                    [0.0, 0.0],
                     [234.5683671466, 88.14896797072]
                 ),
             }
@@ -233,7 +265,7 @@ mod test {
         assert_eq!(
             parse_wcs(wcs_path).unwrap(),
             PlateSolveResult {
-                coord: AstroCoord::from_ra_dec(
+                coord: SkyCoord::from_ra_dec(
                     Angle::from_degrees(212.500334678),
                     Angle::from_degrees(87.87278365695)
                 ),
@@ -244,6 +276,7 @@ mod test {
                         0.006526241730441,
                         -4.221341466003e-5
                     ],
+                    [0.0, 0.0],
                     [212.500334678, 87.87278365695]
                 ),
             }
@@ -24,7 +24,7 @@ pub trait PlateSolver {
     ) -> Result<PlateSolveResult, Self::E>;
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 /// Results of plate solving an image
 pub struct PlateSolveResult {
     pub coord: SkyCoord,
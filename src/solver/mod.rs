@@ -0,0 +1,4 @@
+//! Plate solving backends and shared primitives
+
+pub mod astap;
+pub mod common;